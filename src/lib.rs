@@ -14,9 +14,7 @@
 //! Before:
 //!
 //! ```
-//! fn main() {
-//!     println!("{}", include_str!("greeting.txt"));
-//! }
+//! println!("{}", include_str!("greeting.txt"));
 //! ```
 //!
 //! After:
@@ -30,7 +28,7 @@
 //! }
 //! ```
 
-use std::{path::{Path, PathBuf}, fs::File, io::Read, str};
+use std::{collections::HashMap, path::{Path, PathBuf}, fs::File, io::Read, str, sync::{Arc, Mutex, OnceLock}};
 
 #[doc(hidden)]
 pub fn resolve_path(base: &str, rel: &str) -> Result<PathBuf, &'static str> {
@@ -39,6 +37,11 @@ pub fn resolve_path(base: &str, rel: &str) -> Result<PathBuf, &'static str> {
         .join(rel))
 }
 
+#[doc(hidden)]
+pub fn resolve_path_from_root(manifest_dir: &str, rel: &str) -> Result<PathBuf, &'static str> {
+    Ok(Path::new(manifest_dir).join(rel))
+}
+
 #[doc(hidden)]
 pub fn load_file_bytes(path: &Path) -> Result<&'static [u8], &'static str> {
     let mut f = File::open(path).map_err(|_| "file not found")?;
@@ -58,6 +61,77 @@ pub fn load_file_str(path: &Path) -> Result<&'static str, &'static str> {
     Ok(s)
 }
 
+#[doc(hidden)]
+pub fn load_file_bytes_cached(path: &Path) -> Result<&'static [u8], &'static str> {
+    static CACHE: OnceLock<Mutex<HashMap<PathBuf, &'static [u8]>>> = OnceLock::new();
+    let cache = CACHE.get_or_init(|| Mutex::new(HashMap::new()));
+
+    let mut cache = cache.lock().map_err(|_| "cache lock poisoned")?;
+    if let Some(&bytes) = cache.get(path) {
+        return Ok(bytes);
+    }
+
+    let bytes = load_file_bytes(path)?;
+    cache.insert(path.to_path_buf(), bytes);
+    Ok(bytes)
+}
+
+#[doc(hidden)]
+pub fn load_file_bytes_aligned(path: &Path, align: usize) -> Result<&'static [u8], &'static str> {
+    if !align.is_power_of_two() {
+        return Err("alignment must be a power of two");
+    }
+
+    let mut f = File::open(path).map_err(|_| "file not found")?;
+
+    let mut contents = Vec::new();
+    f.read_to_end(&mut contents)
+        .map_err(|_| "unable to read the file")?;
+
+    let len = contents.len();
+    if len == 0 {
+        return Err("cannot align a zero-length file");
+    }
+
+    let layout = std::alloc::Layout::from_size_align(len, align)
+        .map_err(|_| "invalid alignment layout")?;
+
+    // Allocate with the requested alignment, copy the contents in, and leak
+    // the pointer as a slice so that its data pointer satisfies the alignment.
+    let slice = unsafe {
+        let ptr = std::alloc::alloc(layout);
+        if ptr.is_null() {
+            std::alloc::handle_alloc_error(layout);
+        }
+        std::ptr::copy_nonoverlapping(contents.as_ptr(), ptr, len);
+        std::slice::from_raw_parts(ptr, len)
+    };
+
+    Ok(slice)
+}
+
+#[doc(hidden)]
+pub fn load_file_bytes_deflate(path: &Path) -> Result<&'static [u8], &'static str> {
+    let mut f = File::open(path).map_err(|_| "file not found")?;
+
+    let mut compressed = Vec::new();
+    f.read_to_end(&mut compressed)
+        .map_err(|_| "unable to read the file")?;
+
+    let contents = miniz_oxide::inflate::decompress_to_vec(&compressed)
+        .map_err(|_| "failed to inflate")?;
+
+    let contents = contents.into_boxed_slice();
+    Ok(Box::leak(contents))
+}
+
+#[doc(hidden)]
+pub fn load_file_str_deflate(path: &Path) -> Result<&'static str, &'static str> {
+    let bytes = load_file_bytes_deflate(path)?;
+    let s = str::from_utf8(bytes).map_err(|_| "invalid utf8")?;
+    Ok(s)
+}
+
 /// Load a file as a reference to a byte array at run-time.
 ///
 /// The file is located relative to the current source file, and the binary
@@ -94,12 +168,75 @@ pub fn load_file_str(path: &Path) -> Result<&'static str, &'static str> {
 /// }
 /// ```
 ///
+/// # Compile-time embedding in release builds
+/// By default the macro switches behavior based on the build profile: in
+/// release builds (`not(debug_assertions)`) it expands to a plain
+/// `include_bytes!`, embedding the file at compile time so the shipped binary
+/// performs no file I/O and leaks no memory. In debug builds it keeps the
+/// run-time `resolve_path` + `load_file_bytes` behavior described above, so
+/// assets can be iterated on without recompiling.
+///
+/// The `load_file/force-static` and `load_file/force-dynamic` cargo features
+/// override the profile-based choice, selecting the compile-time or run-time
+/// branch unconditionally. These features are resolved in `load_file` itself —
+/// the macro definition is gated on them with `#[cfg(...)]`, so the static
+/// variant never references `File` or `Box::leak` — while the profile split on
+/// `debug_assertions` is resolved at the call site against the consuming
+/// crate's build profile.
+///
+/// Because the static branch expands to `include_bytes!`, the `$name`
+/// argument must be a string literal whenever that branch can be selected, as
+/// `include_bytes!` does not accept arbitrary expressions. A non-literal
+/// argument therefore compiles in a debug build (which takes the dynamic
+/// branch) but fails to build in release (or with `force-static`). Pass a
+/// string literal to keep both profiles building.
+///
 /// # Panics
 /// To facilitate using `load_bytes!` as a drop-in replacement for
 /// `include_bytes!`, all error situations cause panics:
 ///
 ///  * File not found
 ///  * Read errors
+#[cfg(not(any(feature = "force-static", feature = "force-dynamic")))]
+#[macro_export]
+macro_rules! load_bytes {
+    ($name:expr) => {{
+        #[cfg(not(debug_assertions))]
+        let result: &'static [u8] = include_bytes!($name);
+
+        #[cfg(debug_assertions)]
+        let result: &'static [u8] = {
+            let path = match $crate::resolve_path(file!(), $name) {
+                Ok(x) => x,
+                Err(msg) => {
+                    panic!(format!("{} in load_bytes!({:?})", msg, $name));
+                }
+            };
+            match $crate::load_file_bytes(&path) {
+                Ok(x) => x,
+                Err(msg) => {
+                    panic!(format!("{} in load_bytes!({:?}) (resolved to: {:?})",
+                        msg, $name, path));
+                }
+            }
+        };
+
+        result
+    }};
+}
+
+/// `force-static` override: always embed at compile time. See [`load_bytes!`].
+#[cfg(feature = "force-static")]
+#[macro_export]
+macro_rules! load_bytes {
+    ($name:expr) => {{
+        let result: &'static [u8] = include_bytes!($name);
+        result
+    }};
+}
+
+/// `force-dynamic` override: always load at run-time. See [`load_bytes!`].
+#[cfg(all(feature = "force-dynamic", not(feature = "force-static")))]
 #[macro_export]
 macro_rules! load_bytes {
     ($name:expr) => {{
@@ -152,6 +289,29 @@ macro_rules! load_bytes {
 /// }
 /// ```
 ///
+/// # Compile-time embedding in release builds
+/// By default the macro switches behavior based on the build profile: in
+/// release builds (`not(debug_assertions)`) it expands to a plain
+/// `include_str!`, embedding the file at compile time so the shipped binary
+/// performs no file I/O and leaks no memory. In debug builds it keeps the
+/// run-time `resolve_path` + `load_file_str` behavior described above, so
+/// assets can be iterated on without recompiling.
+///
+/// The `load_file/force-static` and `load_file/force-dynamic` cargo features
+/// override the profile-based choice, selecting the compile-time or run-time
+/// branch unconditionally. These features are resolved in `load_file` itself —
+/// the macro definition is gated on them with `#[cfg(...)]`, so the static
+/// variant never references `File` or `Box::leak` — while the profile split on
+/// `debug_assertions` is resolved at the call site against the consuming
+/// crate's build profile.
+///
+/// Because the static branch expands to `include_str!`, the `$name` argument
+/// must be a string literal whenever that branch can be selected, as
+/// `include_str!` does not accept arbitrary expressions. A non-literal
+/// argument therefore compiles in a debug build (which takes the dynamic
+/// branch) but fails to build in release (or with `force-static`). Pass a
+/// string literal to keep both profiles building.
+///
 /// # Panics
 /// To facilitate using `load_str!` as a drop-in replacement for
 /// `include_str!`, all error situations cause panics:
@@ -159,6 +319,46 @@ macro_rules! load_bytes {
 ///  * File not found
 ///  * Read errors
 ///  * UTF-8 validation errors
+#[cfg(not(any(feature = "force-static", feature = "force-dynamic")))]
+#[macro_export]
+macro_rules! load_str {
+    ($name:expr) => {{
+        #[cfg(not(debug_assertions))]
+        let result: &'static str = include_str!($name);
+
+        #[cfg(debug_assertions)]
+        let result: &'static str = {
+            let path = match $crate::resolve_path(file!(), $name) {
+                Ok(x) => x,
+                Err(msg) => {
+                    panic!(format!("{} in load_str!({:?})", msg, $name));
+                }
+            };
+            match $crate::load_file_str(&path) {
+                Ok(x) => x,
+                Err(msg) => {
+                    panic!(format!("{} in load_str!({:?}) (resolved to: {:?})",
+                        msg, $name, path));
+                }
+            }
+        };
+
+        result
+    }};
+}
+
+/// `force-static` override: always embed at compile time. See [`load_str!`].
+#[cfg(feature = "force-static")]
+#[macro_export]
+macro_rules! load_str {
+    ($name:expr) => {{
+        let result: &'static str = include_str!($name);
+        result
+    }};
+}
+
+/// `force-dynamic` override: always load at run-time. See [`load_str!`].
+#[cfg(all(feature = "force-dynamic", not(feature = "force-static")))]
 #[macro_export]
 macro_rules! load_str {
     ($name:expr) => {{
@@ -177,3 +377,589 @@ macro_rules! load_str {
         }
     }};
 }
+
+/// Load a file as a reference to a byte array at run-time, resolving the path
+/// against the crate root.
+///
+/// Unlike `load_bytes!`, the path is located relative to the crate root
+/// captured at compile time via `env!("CARGO_MANIFEST_DIR")`, rather than
+/// relative to the current source file. This means the assets are found
+/// regardless of the working directory the binary is run from.
+///
+/// The resulting value is a `&'static [u8]` with the contents of the file.
+///
+/// Apart from the anchoring of the path, this macro behaves like the run-time
+/// branch of `load_bytes!`. It always loads at run-time; it does not switch to
+/// compile-time embedding in release builds or with `force-static`.
+///
+/// # Example
+/// ```no_run
+/// #[macro_use]
+/// extern crate load_file;
+///
+/// fn main() {
+///     let greeting: &[u8] = load_bytes_root!("greeting.txt");
+///     println!("{:?}", greeting);
+/// }
+/// ```
+///
+/// # Panics
+/// As with `load_bytes!`, all error situations cause panics:
+///
+///  * File not found
+///  * Read errors
+#[macro_export]
+macro_rules! load_bytes_root {
+    ($name:expr) => {{
+        let path = match $crate::resolve_path_from_root(env!("CARGO_MANIFEST_DIR"), $name) {
+            Ok(x) => x,
+            Err(msg) => {
+                panic!(format!("{} in load_bytes_root!({:?})", msg, $name));
+            }
+        };
+        match $crate::load_file_bytes(&path) {
+            Ok(x) => x,
+            Err(msg) => {
+                panic!(format!("{} in load_bytes_root!({:?}) (resolved to: {:?})",
+                    msg, $name, path));
+            }
+        }
+    }};
+}
+
+/// Load a utf8-encoded file as a string at run-time, resolving the path
+/// against the crate root.
+///
+/// Unlike `load_str!`, the path is located relative to the crate root
+/// captured at compile time via `env!("CARGO_MANIFEST_DIR")`, rather than
+/// relative to the current source file. This means the assets are found
+/// regardless of the working directory the binary is run from.
+///
+/// The resulting value is a `&'static str` with the contents of the file.
+///
+/// Apart from the anchoring of the path, this macro behaves like the run-time
+/// branch of `load_str!`. It always loads at run-time; it does not switch to
+/// compile-time embedding in release builds or with `force-static`.
+///
+/// # Example
+/// ```no_run
+/// #[macro_use]
+/// extern crate load_file;
+///
+/// fn main() {
+///     let greeting: &str = load_str_root!("greeting.txt");
+///     println!("{}", greeting);
+/// }
+/// ```
+///
+/// # Panics
+/// As with `load_str!`, all error situations cause panics:
+///
+///  * File not found
+///  * Read errors
+///  * UTF-8 validation errors
+#[macro_export]
+macro_rules! load_str_root {
+    ($name:expr) => {{
+        let path = match $crate::resolve_path_from_root(env!("CARGO_MANIFEST_DIR"), $name) {
+            Ok(x) => x,
+            Err(msg) => {
+                panic!(format!("{} in load_str_root!({:?})", msg, $name));
+            }
+        };
+        match $crate::load_file_str(&path) {
+            Ok(x) => x,
+            Err(msg) => {
+                panic!(format!("{} in load_str_root!({:?}) (resolved to: {:?})",
+                    msg, $name, path));
+            }
+        }
+    }};
+}
+
+/// Load a DEFLATE-compressed file as a reference to a byte array at run-time.
+///
+/// The file is located relative to the current source file, and the binary
+/// must be run with the crate root as the working directory. Unlike
+/// `load_bytes!`, the file contents are expected to be a raw DEFLATE stream,
+/// which is inflated before being returned.
+///
+/// The resulting value is a `&'static [u8]` with the inflated contents of the
+/// file. This makes it possible to ship a compressed assets directory while
+/// keeping the same ergonomic loading.
+///
+/// As with `load_bytes!`, the inflated buffer is read into memory in its
+/// entirety and leaked, keeping the memory valid for the remainder of the
+/// program execution.
+///
+/// # Example
+/// ```no_run
+/// #[macro_use]
+/// extern crate load_file;
+///
+/// fn main() {
+///     let greeting: &[u8] = load_bytes_deflate!("greeting.txt.deflate");
+///     println!("{:?}", greeting);
+/// }
+/// ```
+///
+/// # Panics
+/// As with `load_bytes!`, all error situations cause panics:
+///
+///  * File not found
+///  * Read errors
+///  * Inflation errors (bad or truncated stream)
+#[macro_export]
+macro_rules! load_bytes_deflate {
+    ($name:expr) => {{
+        let path = match $crate::resolve_path(file!(), $name) {
+            Ok(x) => x,
+            Err(msg) => {
+                panic!(format!("{} in load_bytes_deflate!({:?})", msg, $name));
+            }
+        };
+        match $crate::load_file_bytes_deflate(&path) {
+            Ok(x) => x,
+            Err(msg) => {
+                panic!(format!("{} in load_bytes_deflate!({:?}) (resolved to: {:?})",
+                    msg, $name, path));
+            }
+        }
+    }};
+}
+
+/// Load a DEFLATE-compressed, utf8-encoded file as a string at run-time.
+///
+/// The file is located relative to the current source file, and the binary
+/// must be run with the crate root as the working directory. Unlike
+/// `load_str!`, the file contents are expected to be a raw DEFLATE stream,
+/// which is inflated and then validated as UTF-8 before being returned.
+///
+/// The resulting value is a `&'static str` with the inflated contents of the
+/// file. This makes it possible to ship a compressed assets directory while
+/// keeping the same ergonomic loading.
+///
+/// As with `load_str!`, the inflated buffer is read into memory in its
+/// entirety and leaked, keeping the memory valid for the remainder of the
+/// program execution.
+///
+/// # Example
+/// ```no_run
+/// #[macro_use]
+/// extern crate load_file;
+///
+/// fn main() {
+///     let greeting: &str = load_str_deflate!("greeting.txt.deflate");
+///     println!("{}", greeting);
+/// }
+/// ```
+///
+/// # Panics
+/// As with `load_str!`, all error situations cause panics:
+///
+///  * File not found
+///  * Read errors
+///  * Inflation errors (bad or truncated stream)
+///  * UTF-8 validation errors
+#[macro_export]
+macro_rules! load_str_deflate {
+    ($name:expr) => {{
+        let path = match $crate::resolve_path(file!(), $name) {
+            Ok(x) => x,
+            Err(msg) => {
+                panic!(format!("{} in load_str_deflate!({:?})", msg, $name));
+            }
+        };
+        match $crate::load_file_str_deflate(&path) {
+            Ok(x) => x,
+            Err(msg) => {
+                panic!(format!("{} in load_str_deflate!({:?}) (resolved to: {:?})",
+                    msg, $name, path));
+            }
+        }
+    }};
+}
+
+/// Load a file as a reference to a byte array at run-time, caching the result.
+///
+/// The file is located relative to the current source file, and the binary
+/// must be run with the crate root as the working directory.
+///
+/// The resulting value is a `&'static [u8]` with the contents of the file.
+///
+/// Unlike `load_bytes!`, which re-reads and leaks a fresh allocation on every
+/// invocation, this macro is backed by a process-global cache keyed by the
+/// resolved path. The first time a given path is loaded the file is read,
+/// leaked, and stored in the cache; subsequent invocations with the same
+/// resolved path return the already-leaked reference without re-reading the
+/// file. This bounds leakage to a single allocation per distinct file and
+/// avoids redundant syscalls on hot paths.
+///
+/// # Example
+/// ```
+/// #[macro_use]
+/// extern crate load_file;
+///
+/// fn main() {
+///     for _ in 0..10 {
+///         let greeting: &[u8] = load_bytes_cached!("greeting.txt");
+///         println!("{:?}", greeting);
+///     }
+/// }
+/// ```
+///
+/// # Panics
+/// As with `load_bytes!`, all error situations cause panics:
+///
+///  * File not found
+///  * Read errors
+#[macro_export]
+macro_rules! load_bytes_cached {
+    ($name:expr) => {{
+        let path = match $crate::resolve_path(file!(), $name) {
+            Ok(x) => x,
+            Err(msg) => {
+                panic!(format!("{} in load_bytes_cached!({:?})", msg, $name));
+            }
+        };
+        match $crate::load_file_bytes_cached(&path) {
+            Ok(x) => x,
+            Err(msg) => {
+                panic!(format!("{} in load_bytes_cached!({:?}) (resolved to: {:?})",
+                    msg, $name, path));
+            }
+        }
+    }};
+}
+
+/// Load a file as a reference to a byte array with a guaranteed alignment.
+///
+/// The file is located relative to the current source file, and the binary
+/// must be run with the crate root as the working directory.
+///
+/// The resulting value is a `&'static [u8]` whose backing allocation is
+/// guaranteed to start at the requested `$align` boundary, i.e. its data
+/// pointer satisfies `ptr as usize % $align == 0`. Unlike `load_bytes!`,
+/// which relies on `Vec`'s default alignment, this lets callers safely
+/// reinterpret the blob as a slice of `#[repr(C)]` structs or feed it to
+/// SIMD/GPU-upload code without copying.
+///
+/// As with `load_bytes!`, the file is read into memory in its entirety and
+/// the memory is leaked, keeping it valid for the remainder of the program
+/// execution.
+///
+/// # Example
+/// ```no_run
+/// #[macro_use]
+/// extern crate load_file;
+///
+/// fn main() {
+///     let data: &[u8] = load_bytes_aligned!("mesh.bin", 16);
+///     assert_eq!(data.as_ptr() as usize % 16, 0);
+/// }
+/// ```
+///
+/// # Panics
+/// In addition to the error situations of `load_bytes!`, this macro panics if
+/// `$align` is not a power of two or if the file is empty:
+///
+///  * File not found
+///  * Read errors
+///  * `$align` is not a power of two
+///  * The file is zero-length
+#[macro_export]
+macro_rules! load_bytes_aligned {
+    ($name:expr, $align:expr) => {{
+        let path = match $crate::resolve_path(file!(), $name) {
+            Ok(x) => x,
+            Err(msg) => {
+                panic!(format!("{} in load_bytes_aligned!({:?})", msg, $name));
+            }
+        };
+        match $crate::load_file_bytes_aligned(&path, $align) {
+            Ok(x) => x,
+            Err(msg) => {
+                panic!(format!("{} in load_bytes_aligned!({:?}) (resolved to: {:?})",
+                    msg, $name, path));
+            }
+        }
+    }};
+}
+
+#[doc(hidden)]
+pub fn reload_bytes(path: &Path) -> Result<Arc<[u8]>, &'static str> {
+    let mut f = File::open(path).map_err(|_| "file not found")?;
+
+    let mut contents = Vec::new();
+    f.read_to_end(&mut contents)
+        .map_err(|_| "unable to read the file")?;
+
+    Ok(Arc::from(contents))
+}
+
+#[doc(hidden)]
+pub fn reload_str(path: &Path) -> Result<Arc<str>, &'static str> {
+    let mut f = File::open(path).map_err(|_| "file not found")?;
+
+    let mut contents = Vec::new();
+    f.read_to_end(&mut contents)
+        .map_err(|_| "unable to read the file")?;
+
+    let contents = String::from_utf8(contents).map_err(|_| "invalid utf8")?;
+    Ok(Arc::from(contents))
+}
+
+struct ReloadState<T: ?Sized> {
+    modified: Option<std::time::SystemTime>,
+    cache: Arc<T>,
+}
+
+/// A handle to an on-disk asset that reloads itself when the file changes.
+///
+/// Unlike the one-shot `load_*` macros, which read and leak a single snapshot
+/// of a file, a `ReloadableAsset` keeps the program's reference to a file live:
+/// each call to [`get`](ReloadableAsset::get) stats the file and, if its
+/// modification time has advanced since the last read, re-reads the file and
+/// swaps in the new contents. This makes it suitable for live-reloading
+/// shaders, CSS, templates and similar assets while the program keeps running.
+///
+/// Construct one with the [`watch_file!`](crate::watch_file) or
+/// [`reloadable_str!`](crate::reloadable_str) macros rather than directly.
+pub struct ReloadableAsset<T: ?Sized> {
+    path: PathBuf,
+    load: fn(&Path) -> Result<Arc<T>, &'static str>,
+    state: Mutex<ReloadState<T>>,
+}
+
+impl<T: ?Sized> ReloadableAsset<T> {
+    #[doc(hidden)]
+    pub fn new(
+        path: PathBuf,
+        load: fn(&Path) -> Result<Arc<T>, &'static str>,
+    ) -> Result<ReloadableAsset<T>, &'static str> {
+        let cache = load(&path)?;
+        let modified = std::fs::metadata(&path)
+            .ok()
+            .and_then(|m| m.modified().ok());
+        Ok(ReloadableAsset {
+            path,
+            load,
+            state: Mutex::new(ReloadState { modified, cache }),
+        })
+    }
+
+    /// Return the current contents of the asset, reloading from disk first if
+    /// the file has been modified since the last read.
+    ///
+    /// If the file cannot be stat'ed, the previously cached value is returned
+    /// without a reload attempt and without a warning. If it cannot be re-read
+    /// or (for the string variant) decoded, the previously cached value is kept
+    /// and returned, and a warning is logged to standard error rather than
+    /// panicking.
+    pub fn get(&self) -> Arc<T> {
+        let modified = std::fs::metadata(&self.path)
+            .ok()
+            .and_then(|m| m.modified().ok());
+
+        let mut state = self.state.lock().unwrap();
+
+        let is_newer = match (modified, state.modified) {
+            (Some(new), Some(old)) => new > old,
+            (Some(_), None) => true,
+            _ => false,
+        };
+
+        if is_newer {
+            match (self.load)(&self.path) {
+                Ok(fresh) => {
+                    state.cache = fresh;
+                    state.modified = modified;
+                }
+                Err(msg) => {
+                    eprintln!("load_file: failed to reload {:?}: {}", self.path, msg);
+                }
+            }
+        }
+
+        state.cache.clone()
+    }
+}
+
+/// Create a [`ReloadableAsset`] that reloads a file as raw bytes.
+///
+/// The file is located relative to the current source file, and the binary
+/// must be run with the crate root as the working directory. The returned
+/// `ReloadableAsset<[u8]>` re-reads the file whenever its modification time
+/// advances; see [`ReloadableAsset::get`].
+///
+/// # Example
+/// ```no_run
+/// #[macro_use]
+/// extern crate load_file;
+///
+/// fn main() {
+///     let shader = watch_file!("shader.glsl");
+///     loop {
+///         let source = shader.get();
+///         // ... recompile the shader from `source` on each frame ...
+///         # break;
+///     }
+/// }
+/// ```
+///
+/// # Panics
+/// The initial load panics on the same error situations as `load_bytes!`:
+///
+///  * File not found
+///  * Read errors
+///
+/// Subsequent reloads never panic; errors keep the previous value and log a
+/// warning instead.
+#[macro_export]
+macro_rules! watch_file {
+    ($name:expr) => {{
+        let path = match $crate::resolve_path(file!(), $name) {
+            Ok(x) => x,
+            Err(msg) => {
+                panic!(format!("{} in watch_file!({:?})", msg, $name));
+            }
+        };
+        match $crate::ReloadableAsset::new(path.clone(), $crate::reload_bytes) {
+            Ok(x) => x,
+            Err(msg) => {
+                panic!(format!("{} in watch_file!({:?}) (resolved to: {:?})",
+                    msg, $name, path));
+            }
+        }
+    }};
+}
+
+/// Create a [`ReloadableAsset`] that reloads a utf8-encoded file as a string.
+///
+/// The file is located relative to the current source file, and the binary
+/// must be run with the crate root as the working directory. The returned
+/// `ReloadableAsset<str>` re-reads the file whenever its modification time
+/// advances; see [`ReloadableAsset::get`].
+///
+/// # Example
+/// ```no_run
+/// #[macro_use]
+/// extern crate load_file;
+///
+/// fn main() {
+///     let template = reloadable_str!("page.html");
+///     loop {
+///         let html = template.get();
+///         // ... re-render the page from `html` ...
+///         # break;
+///     }
+/// }
+/// ```
+///
+/// # Panics
+/// The initial load panics on the same error situations as `load_str!`:
+///
+///  * File not found
+///  * Read errors
+///  * UTF-8 validation errors
+///
+/// Subsequent reloads never panic; errors keep the previous value and log a
+/// warning instead.
+#[macro_export]
+macro_rules! reloadable_str {
+    ($name:expr) => {{
+        let path = match $crate::resolve_path(file!(), $name) {
+            Ok(x) => x,
+            Err(msg) => {
+                panic!(format!("{} in reloadable_str!({:?})", msg, $name));
+            }
+        };
+        match $crate::ReloadableAsset::new(path.clone(), $crate::reload_str) {
+            Ok(x) => x,
+            Err(msg) => {
+                panic!(format!("{} in reloadable_str!({:?}) (resolved to: {:?})",
+                    msg, $name, path));
+            }
+        }
+    }};
+}
+
+/// Load a file as a reference to a byte array at run-time, returning a
+/// `Result` instead of panicking.
+///
+/// This is the non-panicking counterpart to `load_bytes!`, sharing the same
+/// `resolve_path` and loading logic. It yields a
+/// `Result<&'static [u8], &'static str>`, so applications loading optional or
+/// user-supplied assets can handle a missing file by matching on the error
+/// rather than unwinding.
+///
+/// # Example
+/// ```
+/// #[macro_use]
+/// extern crate load_file;
+///
+/// fn main() {
+///     let greeting: &[u8] = match try_load_bytes!("greeting.txt") {
+///         Ok(bytes) => bytes,
+///         Err(_) => b"default",
+///     };
+///     println!("{:?}", greeting);
+/// }
+/// ```
+#[macro_export]
+macro_rules! try_load_bytes {
+    ($name:expr) => {
+        $crate::resolve_path(file!(), $name)
+            .and_then(|path| $crate::load_file_bytes(&path))
+    };
+}
+
+/// Load a utf8-encoded file as a string at run-time, returning a `Result`
+/// instead of panicking.
+///
+/// This is the non-panicking counterpart to `load_str!`, sharing the same
+/// `resolve_path` and loading logic. It yields a
+/// `Result<&'static str, &'static str>`, so applications loading optional or
+/// user-supplied assets can handle a missing file or invalid UTF-8 by matching
+/// on the error rather than unwinding.
+///
+/// # Example
+/// ```
+/// #[macro_use]
+/// extern crate load_file;
+///
+/// fn main() {
+///     let greeting: &str = match try_load_str!("greeting.txt") {
+///         Ok(s) => s,
+///         Err(_) => "default",
+///     };
+///     println!("{}", greeting);
+/// }
+/// ```
+#[macro_export]
+macro_rules! try_load_str {
+    ($name:expr) => {
+        $crate::resolve_path(file!(), $name)
+            .and_then(|path| $crate::load_file_str(&path))
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::Path;
+
+    #[test]
+    fn load_file_bytes_aligned_is_aligned_and_correct() {
+        let path = Path::new(concat!(env!("CARGO_MANIFEST_DIR"), "/src/greeting.txt"));
+        let data = crate::load_file_bytes_aligned(path, 64).unwrap();
+
+        assert_eq!(data.as_ptr() as usize % 64, 0);
+        assert_eq!(data, include_bytes!("greeting.txt"));
+    }
+
+    #[test]
+    fn load_file_bytes_aligned_rejects_non_power_of_two() {
+        let path = Path::new(concat!(env!("CARGO_MANIFEST_DIR"), "/src/greeting.txt"));
+        assert!(crate::load_file_bytes_aligned(path, 3).is_err());
+    }
+}